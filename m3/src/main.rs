@@ -1,5 +1,6 @@
-use iced::widget::{column, container, text_input, text};
-use iced::{Theme, Element, Error, Alignment, Color, Length, Font, Point};
+use iced::widget::{button, column, container, row, scrollable, text_input, text};
+use iced::{Theme, Element, Error, Alignment, Color, Length, Font, Point, Size};
+use std::cell::Cell;
 use iced::theme;
 use iced::{keyboard, Event, Subscription, window, mouse, time};
 use iced::event;
@@ -8,6 +9,8 @@ use plotters::style::Color as PlottersColor;
 use plotters_iced::{Chart, ChartWidget, DrawingBackend, ChartBuilder};
 use std::path::PathBuf;
 use iced::{Task};
+use iced::futures::{SinkExt, Stream, StreamExt};
+use iced::futures::channel::mpsc;
 use std::time::{Duration, Instant};
 
 // Custom deserialization for the timestamp
@@ -28,6 +31,170 @@ mod custom_date_format {
     }
 }
 
+// Technical-indicator computations. Each returns one value per input close,
+// with `None` for the leading points where the indicator is still undefined.
+mod indicators {
+    // SMA(n): mean of the last `n` closes ending at each index.
+    pub fn sma(closes: &[f64], n: usize) -> Vec<Option<f64>> {
+        let mut out = vec![None; closes.len()];
+        if n == 0 {
+            return out;
+        }
+        let mut sum = 0.0;
+        for i in 0..closes.len() {
+            sum += closes[i];
+            if i >= n {
+                sum -= closes[i - n];
+            }
+            if i + 1 >= n {
+                out[i] = Some(sum / n as f64);
+            }
+        }
+        out
+    }
+
+    // EMA(n): seeded with the SMA of the first `n` closes, then the recurrence
+    // `EMA_t = close_t*k + EMA_{t-1}*(1-k)` with `k = 2/(n+1)`.
+    pub fn ema(closes: &[f64], n: usize) -> Vec<Option<f64>> {
+        let mut out = vec![None; closes.len()];
+        if n == 0 || closes.len() < n {
+            return out;
+        }
+        let k = 2.0 / (n as f64 + 1.0);
+        let mut prev = closes[..n].iter().sum::<f64>() / n as f64;
+        out[n - 1] = Some(prev);
+        for i in n..closes.len() {
+            prev = closes[i] * k + prev * (1.0 - k);
+            out[i] = Some(prev);
+        }
+        out
+    }
+
+    // Bollinger Bands: middle = SMA(n); upper/lower = middle ± `mult` times the
+    // population standard deviation of the same `n`-close window.
+    pub fn bollinger(
+        closes: &[f64],
+        n: usize,
+        mult: f64,
+    ) -> (Vec<Option<f64>>, Vec<Option<f64>>, Vec<Option<f64>>) {
+        let middle = sma(closes, n);
+        let mut upper = vec![None; closes.len()];
+        let mut lower = vec![None; closes.len()];
+        if n == 0 {
+            return (middle, upper, lower);
+        }
+        for i in 0..closes.len() {
+            if let Some(mean) = middle[i] {
+                let window = &closes[i + 1 - n..=i];
+                let var = window.iter().map(|c| (c - mean).powi(2)).sum::<f64>() / n as f64;
+                let sd = var.sqrt();
+                upper[i] = Some(mean + mult * sd);
+                lower[i] = Some(mean - mult * sd);
+            }
+        }
+        (middle, upper, lower)
+    }
+
+    // RSI(n) with Wilder smoothing: seed avgGain/avgLoss as the simple mean of
+    // the first `n` changes, then smooth with `avg_t = (avg_{t-1}*(n-1) + x_t)/n`.
+    pub fn rsi(closes: &[f64], n: usize) -> Vec<Option<f64>> {
+        let mut out = vec![None; closes.len()];
+        if n == 0 || closes.len() <= n {
+            return out;
+        }
+        // Per-day gain/loss; index `i` is the change from `i-1` to `i`.
+        let mut gains = vec![0.0; closes.len()];
+        let mut losses = vec![0.0; closes.len()];
+        for i in 1..closes.len() {
+            let change = closes[i] - closes[i - 1];
+            gains[i] = change.max(0.0);
+            losses[i] = (-change).max(0.0);
+        }
+        let mut avg_gain = gains[1..=n].iter().sum::<f64>() / n as f64;
+        let mut avg_loss = losses[1..=n].iter().sum::<f64>() / n as f64;
+        out[n] = Some(rsi_from(avg_gain, avg_loss));
+        for i in (n + 1)..closes.len() {
+            avg_gain = (avg_gain * (n as f64 - 1.0) + gains[i]) / n as f64;
+            avg_loss = (avg_loss * (n as f64 - 1.0) + losses[i]) / n as f64;
+            out[i] = Some(rsi_from(avg_gain, avg_loss));
+        }
+        out
+    }
+
+    fn rsi_from(avg_gain: f64, avg_loss: f64) -> f64 {
+        if avg_loss == 0.0 {
+            100.0
+        } else {
+            let rs = avg_gain / avg_loss;
+            100.0 - 100.0 / (1.0 + rs)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn approx(a: f64, b: f64) {
+            assert!((a - b).abs() < 1e-9, "expected {b}, got {a}");
+        }
+
+        #[test]
+        fn sma_seeds_at_index_n_minus_1() {
+            let out = sma(&[1.0, 2.0, 3.0, 4.0, 5.0], 3);
+            assert_eq!(out[0], None);
+            assert_eq!(out[1], None);
+            approx(out[2].unwrap(), 2.0);
+            approx(out[3].unwrap(), 3.0);
+            approx(out[4].unwrap(), 4.0);
+        }
+
+        #[test]
+        fn ema_seeds_with_sma_at_index_n_minus_1() {
+            // k = 2/(3+1) = 0.5; seed = mean(1,2,3) = 2.0 at index 2.
+            let out = ema(&[1.0, 2.0, 3.0, 4.0, 5.0], 3);
+            assert_eq!(out[0], None);
+            assert_eq!(out[1], None);
+            approx(out[2].unwrap(), 2.0);
+            approx(out[3].unwrap(), 3.0); // 4*0.5 + 2*0.5
+            approx(out[4].unwrap(), 4.0); // 5*0.5 + 3*0.5
+        }
+
+        #[test]
+        fn bollinger_middle_is_sma_and_bands_use_population_sd() {
+            let closes = [2.0, 4.0, 6.0, 8.0, 10.0];
+            let (middle, upper, lower) = bollinger(&closes, 3, 2.0);
+            assert_eq!(middle, sma(&closes, 3));
+            // Window [2,4,6]: mean 4, population var (4+0+4)/3, sd = sqrt(8/3).
+            let sd = (8.0_f64 / 3.0).sqrt();
+            approx(upper[2].unwrap(), 4.0 + 2.0 * sd);
+            approx(lower[2].unwrap(), 4.0 - 2.0 * sd);
+            assert_eq!(upper[1], None);
+        }
+
+        #[test]
+        fn rsi_first_value_at_index_n_and_saturates_on_pure_gains() {
+            // Monotonic rise -> avg_loss == 0 -> RSI 100, first defined at index n.
+            let out = rsi(&[1.0, 2.0, 3.0, 4.0, 5.0], 2);
+            assert_eq!(out[0], None);
+            assert_eq!(out[1], None);
+            approx(out[2].unwrap(), 100.0);
+            approx(out[4].unwrap(), 100.0);
+        }
+
+        #[test]
+        fn rsi_wilder_smoothing_known_value() {
+            // n=2: seed avg over changes at indices 1,2 then smooth at index 3.
+            // changes: +1, -1, +2 -> gains [_,1,0,2], losses [_,0,1,0].
+            // avg_gain0 = 0.5, avg_loss0 = 0.5 -> RSI[2] = 50.
+            // avg_gain1 = (0.5*1 + 2)/2 = 1.25, avg_loss1 = (0.5*1 + 0)/2 = 0.25.
+            // RS = 5 -> RSI[3] = 100 - 100/6.
+            let out = rsi(&[10.0, 11.0, 10.0, 12.0], 2);
+            approx(out[2].unwrap(), 50.0);
+            approx(out[3].unwrap(), 100.0 - 100.0 / 6.0);
+        }
+    }
+}
+
 // Define a struct to hold OHLCV data
 #[derive(Debug, Clone, serde::Deserialize)]
 struct StockData {
@@ -56,10 +223,21 @@ fn main() -> Result<(), Error> {
                 stock_data: Vec::new(),
                 price_chart_state: ChartState::new(ChartType::Price, Vec::new()),
                 volume_chart_state: ChartState::new(ChartType::Volume, Vec::new()),
+                rsi_chart_state: ChartState::new(ChartType::Rsi, Vec::new()),
+                indicators: IndicatorSet::default(),
+                watchlist: Vec::new(),
+                sort_column: SortColumn::Name,
+                sort_ascending: true,
                 is_fullscreen: false,
                 selected_data_point: None,
                 mouse_position: None,
                 last_mouse_update: None,
+                visible_range: (0, 0),
+                drag_anchor: None,
+                window_size: Size::new(1800.0, 900.0),
+                fetch_error: None,
+                harvester: None,
+                refresh_interval: REFRESH_INTERVALS[0],
             };
             let initial_task = Task::perform(
                 load_stock_data("NVDA".to_string()),
@@ -73,6 +251,82 @@ fn main() -> Result<(), Error> {
 enum ChartType {
     Price,
     Volume,
+    Rsi,
+}
+
+// Periods/multipliers for the overlay indicators.
+const SMA_PERIOD: usize = 20;
+const EMA_PERIOD: usize = 20;
+const BOLLINGER_PERIOD: usize = 20;
+const BOLLINGER_MULT: f64 = 2.0;
+const RSI_PERIOD: usize = 14;
+
+// Latest cached snapshot for a watchlist symbol.
+#[derive(Debug, Clone)]
+struct WatchlistEntry {
+    ticker: String,
+    close: f64,
+    change_pct: f64,
+    volume: f64,
+}
+
+// Column the watchlist is ordered by, à la bottom's `ProcessSorting`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SortColumn {
+    Name,
+    Close,
+    Change,
+    Volume,
+}
+
+impl SortColumn {
+    // Column order cycled through when the sort key is advanced by keyboard.
+    fn next(self) -> Self {
+        match self {
+            SortColumn::Name => SortColumn::Close,
+            SortColumn::Close => SortColumn::Change,
+            SortColumn::Change => SortColumn::Volume,
+            SortColumn::Volume => SortColumn::Name,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SortColumn::Name => "Ticker",
+            SortColumn::Close => "Close",
+            SortColumn::Change => "Chg%",
+            SortColumn::Volume => "Volume",
+        }
+    }
+}
+
+// One of the toggleable overlays.
+#[derive(Debug, Clone, Copy)]
+enum Indicator {
+    Sma,
+    Ema,
+    Bollinger,
+    Rsi,
+}
+
+// Which overlays are currently enabled.
+#[derive(Debug, Clone, Copy, Default)]
+struct IndicatorSet {
+    sma: bool,
+    ema: bool,
+    bollinger: bool,
+    rsi: bool,
+}
+
+impl IndicatorSet {
+    fn toggle(&mut self, indicator: Indicator) {
+        match indicator {
+            Indicator::Sma => self.sma = !self.sma,
+            Indicator::Ema => self.ema = !self.ema,
+            Indicator::Bollinger => self.bollinger = !self.bollinger,
+            Indicator::Rsi => self.rsi = !self.rsi,
+        }
+    }
 }
 
 struct StockScreener {
@@ -80,10 +334,48 @@ struct StockScreener {
     stock_data: Vec<StockData>,
     price_chart_state: ChartState,
     volume_chart_state: ChartState,
+    rsi_chart_state: ChartState,
+    indicators: IndicatorSet,
+    // Multi-ticker watchlist: cached latest row per symbol, plus the current
+    // sort column and direction.
+    watchlist: Vec<WatchlistEntry>,
+    sort_column: SortColumn,
+    sort_ascending: bool,
     is_fullscreen: bool,
     selected_data_point: Option<usize>,
     mouse_position: Option<Point>,
     last_mouse_update: Option<Instant>,
+    // Half-open index window `[start, end)` into `stock_data` that is currently
+    // drawn. Zoom/pan mutate this; `DataLoaded` reinitializes it.
+    visible_range: (usize, usize),
+    // Active left-button pan: cursor position and visible range at grab time.
+    drag_anchor: Option<(Point, (usize, usize))>,
+    // Live window size, tracked via `window::Event::Resized` so the cursor->index
+    // mapping stays correct in any window dimension or fullscreen.
+    window_size: Size,
+    // Most recent background/fetch error, surfaced as a stale-data warning in
+    // the status bar while the previous chart data stays on screen.
+    fetch_error: Option<String>,
+    // Sending half of the background harvester's control channel. Populated once
+    // the harvester subscription hands it back via `Message::HarvesterReady`.
+    harvester: Option<mpsc::Sender<HarvesterControl>>,
+    // Current background poll cadence, cycled through `REFRESH_INTERVALS` and
+    // pushed to the harvester via `HarvesterControl::SetRefreshInterval`.
+    refresh_interval: Duration,
+}
+
+// Control messages sent from `update` into the background data-harvester.
+//
+// Mirrors bottom's `ThreadControlEvent`: the app keeps the sending half and
+// retunes the running harvester without tearing down the subscription.
+#[derive(Debug, Clone)]
+enum HarvesterControl {
+    // Force an immediate re-fetch of the active ticker.
+    Reset,
+    // Change how often the active ticker is polled.
+    SetRefreshInterval(Duration),
+    // Switch the symbol the harvester is polling.
+    SetTicker(String),
 }
 
 #[derive(Debug, Clone)]
@@ -91,6 +383,20 @@ enum Message {
     TickerInputChanged(String),
     LoadData,
     DataLoaded(Result<Vec<StockData>, String>),
+    HarvesterReady(mpsc::Sender<HarvesterControl>),
+    RefreshData,
+    FileChanged(String),
+    ScrollZoom(f32),
+    DragStarted,
+    DragEnded,
+    ToggleIndicator(Indicator),
+    AddToWatchlist,
+    WatchlistLoaded(String, Result<Vec<StockData>, String>),
+    SortWatchlist(SortColumn),
+    CycleWatchlistSort,
+    CycleRefreshInterval,
+    SelectTicker(String),
+    WindowResized(Size),
     CloseApp,
     ToggleFullscreen,
     MouseMoved(Point),
@@ -98,6 +404,61 @@ enum Message {
     NoOp,
 }
 
+// Width of the watchlist side column and the padding around the charts column.
+// Together they fix the chart widgets' left edge within the window, which is all
+// we need to turn a window-space cursor x into a widget-relative one; the exact
+// plotting-area bounds come from plotters itself (see `ChartState::plot_px`).
+const PANEL_WIDTH: f32 = 360.0;
+const CONTENT_PADDING: f32 = 20.0;
+const CHART_WIDGET_LEFT: f32 = PANEL_WIDTH + CONTENT_PADDING;
+
+// Zoom sensitivity (window shrinks to ~85% per scroll notch) and the tightest
+// window the user can zoom into.
+const ZOOM_STEP: f64 = 0.85;
+const MIN_VISIBLE: usize = 5;
+
+// Background poll cadences cycled by `Message::CycleRefreshInterval`. The first
+// entry matches the harvester's own startup default.
+const REFRESH_INTERVALS: [Duration; 4] = [
+    Duration::from_secs(60),
+    Duration::from_secs(30),
+    Duration::from_secs(15),
+    Duration::from_secs(5),
+];
+
+// Push the current visible window down into both chart states so their x-axis
+// ranges stay in sync with zoom/pan.
+fn apply_visible_range(state: &mut StockScreener) {
+    state.price_chart_state.set_visible_range(state.visible_range);
+    state.volume_chart_state.set_visible_range(state.visible_range);
+    state.rsi_chart_state.set_visible_range(state.visible_range);
+}
+
+// Push the enabled-indicator set into the chart states that draw overlays.
+fn apply_indicators(state: &mut StockScreener) {
+    state.price_chart_state.set_indicators(state.indicators);
+    state.rsi_chart_state.set_indicators(state.indicators);
+}
+
+// Re-order the watchlist by the active sort column and direction.
+fn sort_watchlist(state: &mut StockScreener) {
+    let ascending = state.sort_ascending;
+    let column = state.sort_column;
+    state.watchlist.sort_by(|a, b| {
+        let ordering = match column {
+            SortColumn::Name => a.ticker.cmp(&b.ticker),
+            SortColumn::Close => a.close.partial_cmp(&b.close).unwrap_or(std::cmp::Ordering::Equal),
+            SortColumn::Change => a.change_pct.partial_cmp(&b.change_pct).unwrap_or(std::cmp::Ordering::Equal),
+            SortColumn::Volume => a.volume.partial_cmp(&b.volume).unwrap_or(std::cmp::Ordering::Equal),
+        };
+        if ascending {
+            ordering
+        } else {
+            ordering.reverse()
+        }
+    });
+}
+
 // Update function for the new Program API
 fn update(state: &mut StockScreener, message: Message) -> Task<Message> {
     match message {
@@ -107,29 +468,220 @@ fn update(state: &mut StockScreener, message: Message) -> Task<Message> {
         }
         Message::LoadData => {
             let ticker = state.ticker_input.to_uppercase();
+            // Point the background harvester at the newly requested symbol so
+            // its poll cadence follows what the user is looking at.
+            if let Some(tx) = state.harvester.as_mut() {
+                let _ = tx.try_send(HarvesterControl::SetTicker(ticker.clone()));
+            }
+            Task::perform(load_stock_data(ticker), Message::DataLoaded)
+        }
+        Message::HarvesterReady(tx) => {
+            state.harvester = Some(tx);
+            // Tell the freshly-started harvester which symbol to poll.
+            if let Some(tx) = state.harvester.as_mut() {
+                let _ = tx.try_send(HarvesterControl::SetTicker(state.ticker_input.to_uppercase()));
+            }
+            Task::none()
+        }
+        Message::RefreshData => {
+            // Nudge the harvester to re-fetch the active ticker immediately.
+            if let Some(tx) = state.harvester.as_mut() {
+                let _ = tx.try_send(HarvesterControl::Reset);
+            }
+            Task::none()
+        }
+        Message::CycleRefreshInterval => {
+            // Advance to the next preset cadence and retune the running harvester
+            // in place, without tearing the subscription down.
+            let next = REFRESH_INTERVALS
+                .iter()
+                .position(|d| *d == state.refresh_interval)
+                .map(|i| (i + 1) % REFRESH_INTERVALS.len())
+                .unwrap_or(0);
+            state.refresh_interval = REFRESH_INTERVALS[next];
+            if let Some(tx) = state.harvester.as_mut() {
+                let _ = tx.try_send(HarvesterControl::SetRefreshInterval(state.refresh_interval));
+            }
+            Task::none()
+        }
+        Message::FileChanged(ticker) => {
+            // The CSV backing the active ticker changed on disk; reload it so
+            // the candlestick and volume charts refresh instantly.
             Task::perform(load_stock_data(ticker), Message::DataLoaded)
         }
+        Message::ScrollZoom(delta) => {
+            let len = state.stock_data.len();
+            if len == 0 {
+                return Task::none();
+            }
+            let (lo, hi) = state.visible_range;
+            let width = (hi - lo).max(1);
+
+            // Keep the index under the cursor fixed while the window grows/shrinks,
+            // using plotters' real plotting-area bounds for the cursor fraction.
+            let ratio = state.mouse_position
+                .and_then(|p| state.price_chart_state.cursor_fraction(p.x))
+                .unwrap_or(0.5);
+            let focus = lo as f64 + ratio * width as f64;
+
+            // Scroll up zooms in (narrower window), scroll down zooms out.
+            let factor = if delta > 0.0 { ZOOM_STEP } else { 1.0 / ZOOM_STEP };
+            let new_width = ((width as f64 * factor).round() as usize).clamp(MIN_VISIBLE.min(len), len);
+            let new_lo = (focus - ratio * new_width as f64)
+                .round()
+                .clamp(0.0, (len - new_width) as f64) as usize;
+
+            state.visible_range = (new_lo, new_lo + new_width);
+            apply_visible_range(state);
+            Task::none()
+        }
+        Message::DragStarted => {
+            // Anchor the pan at the current cursor and window.
+            if let Some(pos) = state.mouse_position {
+                state.drag_anchor = Some((pos, state.visible_range));
+            }
+            Task::none()
+        }
+        Message::DragEnded => {
+            state.drag_anchor = None;
+            Task::none()
+        }
+        Message::ToggleIndicator(indicator) => {
+            state.indicators.toggle(indicator);
+            apply_indicators(state);
+            Task::none()
+        }
+        Message::AddToWatchlist => {
+            let ticker = state.ticker_input.to_uppercase();
+            if ticker.is_empty() {
+                return Task::none();
+            }
+            Task::perform(load_stock_data(ticker.clone()), move |result| {
+                Message::WatchlistLoaded(ticker.clone(), result)
+            })
+        }
+        Message::WatchlistLoaded(ticker, Ok(data)) => {
+            if let Some(last) = data.last() {
+                // Daily % change from the penultimate close, matching the status bar.
+                let prev_close = if data.len() >= 2 {
+                    data[data.len() - 2].close
+                } else {
+                    last.close
+                };
+                let change_pct = if prev_close != 0.0 {
+                    ((last.close - prev_close) / prev_close) * 100.0
+                } else {
+                    0.0
+                };
+                let entry = WatchlistEntry {
+                    ticker: ticker.clone(),
+                    close: last.close,
+                    change_pct,
+                    volume: last.volume,
+                };
+                // Replace any existing snapshot for this symbol.
+                if let Some(existing) = state.watchlist.iter_mut().find(|e| e.ticker == ticker) {
+                    *existing = entry;
+                } else {
+                    state.watchlist.push(entry);
+                }
+                sort_watchlist(state);
+            }
+            Task::none()
+        }
+        Message::WatchlistLoaded(ticker, Err(e)) => {
+            eprintln!("Error loading watchlist symbol {}: {}", ticker, e);
+            Task::none()
+        }
+        Message::SortWatchlist(column) => {
+            // Clicking the active column flips direction; a new column starts ascending.
+            if state.sort_column == column {
+                state.sort_ascending = !state.sort_ascending;
+            } else {
+                state.sort_column = column;
+                state.sort_ascending = true;
+            }
+            sort_watchlist(state);
+            Task::none()
+        }
+        Message::CycleWatchlistSort => {
+            state.sort_column = state.sort_column.next();
+            state.sort_ascending = true;
+            sort_watchlist(state);
+            Task::none()
+        }
+        Message::SelectTicker(ticker) => {
+            // Clicking a watchlist row drives the main charts from that symbol.
+            state.ticker_input = ticker.clone();
+            if let Some(tx) = state.harvester.as_mut() {
+                let _ = tx.try_send(HarvesterControl::SetTicker(ticker.clone()));
+            }
+            Task::perform(load_stock_data(ticker), Message::DataLoaded)
+        }
+        Message::WindowResized(size) => {
+            state.window_size = size;
+            Task::none()
+        }
         Message::DataLoaded(Ok(data)) => {
+            state.fetch_error = None;
+
+            // A background refresh of an unchanged CSV arrives on the same path as
+            // an initial load. Only reset the view when the dataset length actually
+            // changes; otherwise the 60s harvester would wipe the user's zoom/pan
+            // (chunk0-3) and crosshair selection once a minute for no new data.
+            let prev_len = state.stock_data.len();
+            let len = data.len();
             state.stock_data = data;
-            let mut chart_data = Vec::new();
-            if !state.stock_data.is_empty() {
-                let six_months_ago = chrono::Utc::now() - chrono::Duration::days(6 * 30);
-                chart_data = state.stock_data.iter()
-                    .filter(|d| d.timestamp >= six_months_ago)
-                    .cloned()
-                    .collect();
+
+            // Three cases, so a live feed that appends rows does not snap the view
+            // back to the default window every refresh:
+            //   * unchanged length  -> keep the window and selection untouched;
+            //   * appended rows      -> keep the window width and re-anchor it to
+            //                           the new right edge, preserving the selection;
+            //   * initial/other      -> reset to roughly the last six months.
+            let appended = prev_len > 0 && len > prev_len;
+            if len == prev_len {
+                // Nothing to do; the window/selection stay as the user left them.
+            } else if appended {
+                let (lo, hi) = state.visible_range;
+                let width = (hi - lo).min(len);
+                state.visible_range = (len - width, len);
+            } else {
+                // Chart states now hold the full CSV history; `visible_range` picks
+                // the slice that is actually drawn. Default to roughly the last six
+                // months so the initial view matches the old fixed window, but the
+                // user can zoom/pan across the whole history from here.
+                let start = if len == 0 {
+                    0
+                } else {
+                    let six_months_ago = chrono::Utc::now() - chrono::Duration::days(6 * 30);
+                    state.stock_data
+                        .iter()
+                        .position(|d| d.timestamp >= six_months_ago)
+                        .unwrap_or(0)
+                };
+                state.visible_range = (start, len);
+                state.selected_data_point = None;
+                state.drag_anchor = None;
             }
-            state.price_chart_state.update_data(chart_data.clone());
-            state.volume_chart_state.update_data(chart_data);
-            state.selected_data_point = None;
+
+            state.price_chart_state.update_data(state.stock_data.clone());
+            state.volume_chart_state.update_data(state.stock_data.clone());
+            state.rsi_chart_state.update_data(state.stock_data.clone());
+            apply_visible_range(state);
+            apply_indicators(state);
             Task::none()
         }
         Message::DataLoaded(Err(e)) => {
             eprintln!("Error loading data: {}", e);
-            state.stock_data.clear();
-            state.price_chart_state.update_data(Vec::new());
-            state.volume_chart_state.update_data(Vec::new());
-            state.selected_data_point = None;
+            // Keep whatever chart data is already on screen and flag it as stale;
+            // a failed background refresh should not blank the candlesticks.
+            state.fetch_error = Some(e);
+            if state.stock_data.is_empty() {
+                state.price_chart_state.update_data(Vec::new());
+                state.volume_chart_state.update_data(Vec::new());
+                state.selected_data_point = None;
+            }
             Task::none()
         }
         Message::CloseApp => {
@@ -149,10 +701,39 @@ fn update(state: &mut StockScreener, message: Message) -> Task<Message> {
             if position.x < 0.0 || position.y < 0.0 {
                 state.mouse_position = None;
                 state.price_chart_state.set_mouse_position(None);
+                state.volume_chart_state.set_mouse_position(None);
+                state.volume_chart_state.set_selected_index(None);
                 state.selected_data_point = None;
                 return Task::none();
             }
             
+            // While the left button is held, translate cursor movement into a
+            // pan of the visible window rather than a crosshair move.
+            if let Some((anchor_pos, (anchor_lo, anchor_hi))) = state.drag_anchor {
+                let len = state.stock_data.len();
+                if len > 0 {
+                    let width = anchor_hi - anchor_lo;
+                    let dx = position.x - anchor_pos.x;
+                    // Convert the pixel drag into an index delta using the real
+                    // plotting-area width, falling back to the window width right
+                    // of the chart widgets' left edge.
+                    let plot_px = state.price_chart_state
+                        .plot_width_px()
+                        .unwrap_or((state.window_size.width - CHART_WIDGET_LEFT).max(1.0));
+                    let idx_delta = (dx / plot_px) * width as f32;
+                    // Dragging right reveals earlier data, so the window moves left.
+                    let new_lo = (anchor_lo as f32 - idx_delta)
+                        .round()
+                        .clamp(0.0, (len - width) as f32) as usize;
+                    if state.visible_range != (new_lo, new_lo + width) {
+                        state.visible_range = (new_lo, new_lo + width);
+                        apply_visible_range(state);
+                    }
+                }
+                state.mouse_position = Some(position);
+                return Task::none();
+            }
+
             // Increase throttle threshold to reduce jitter more aggressively
             let should_update = if let Some(last_pos) = state.mouse_position {
                 // Only update if mouse moved more than 5 pixels
@@ -164,35 +745,21 @@ fn update(state: &mut StockScreener, message: Message) -> Task<Message> {
             
             if should_update {
                 state.mouse_position = Some(position);
-                
-                // Update only price chart state with mouse position
+
+                // Share the cursor with both panes so their crosshairs stay synced.
                 state.price_chart_state.set_mouse_position(Some(position));
-                
-                // Simplified calculation for data point selection
-                if !state.price_chart_state.data.is_empty() {
-                    let data_count = state.price_chart_state.data.len();
-                    
-                    // Use a much simpler approach - assume chart takes most of the window width
-                    // with some padding on the sides
-                    let chart_left_margin = 60.0;
-                    let chart_right_margin = 60.0;
-                    let window_width = 1800.0;
-                    let chart_width = window_width - chart_left_margin - chart_right_margin;
-                    
-                    if position.x >= chart_left_margin && position.x <= (window_width - chart_right_margin) {
-                        let relative_x = position.x - chart_left_margin;
-                        let ratio = relative_x / chart_width;
-                        let index = (ratio * data_count as f32) as usize;
-                        let clamped_index = index.min(data_count - 1);
-                        
-                        // Only update if the index actually changed
-                        if state.selected_data_point != Some(clamped_index) {
-                            state.selected_data_point = Some(clamped_index);
-                        }
-                    } else {
-                        state.selected_data_point = None;
-                    }
+                state.volume_chart_state.set_mouse_position(Some(position));
+
+                // Invert the precise pixel->index mapping plotters handed back on
+                // the last render; no hard-coded window width anymore.
+                if position.x <= state.window_size.width {
+                    state.selected_data_point = state.price_chart_state.index_at_cursor(position.x);
+                } else {
+                    state.selected_data_point = None;
                 }
+                // Drive the volume pane's crosshair from the price pane's index so
+                // both snap to the same data point despite differing plot offsets.
+                state.volume_chart_state.set_selected_index(state.selected_data_point);
                 state.last_mouse_update = Some(Instant::now());
             }
             Task::none()
@@ -223,7 +790,9 @@ fn view(state: &StockScreener) -> Element<Message> {
         .width(Length::Fill)
         .height(Length::FillPortion(2));
     
-    let status_bar = if let Some(index) = state.selected_data_point {
+    const EMPTY_STATUS: &str = "Date:            | Daily % Gain/Loss:         % | Volume:              | Open:         | High:         | Low:          | Close:        ";
+
+    let base_status = if let Some(index) = state.selected_data_point {
         if let Some(data_point) = state.price_chart_state.data.get(index) {
             let daily_change = if index > 0 {
                 if let Some(prev_data) = state.price_chart_state.data.get(index - 1) {
@@ -234,45 +803,118 @@ fn view(state: &StockScreener) -> Element<Message> {
             } else {
                 0.0
             };
-            
+
             let date_str = data_point.timestamp.format("%Y-%m-%d").to_string();
-            let status_text = format!(
+            format!(
                 "Date: {:>10} | Daily % Gain/Loss: {:>8.2}% | Volume: {:>12.0} | Open: {:>8.2} | High: {:>8.2} | Low: {:>8.2} | Close: {:>8.2}",
                 date_str, daily_change, data_point.volume, data_point.open, data_point.high, data_point.low, data_point.close
-            );
-            
-            text(status_text)
-                .size(14)
-                .font(Font::with_name("JetBrains Mono"))
+            )
         } else {
-            text("Date:            | Daily % Gain/Loss:         % | Volume:              | Open:         | High:         | Low:          | Close:        ")
-                .size(14)
-                .font(Font::with_name("JetBrains Mono"))
+            EMPTY_STATUS.to_string()
         }
     } else {
-        text("Date:            | Daily % Gain/Loss:         % | Volume:              | Open:         | High:         | Low:          | Close:        ")
+        EMPTY_STATUS.to_string()
+    };
+
+    // A failed (re)fetch leaves the previous data on screen; warn that it is stale.
+    let status_bar = if let Some(error) = &state.fetch_error {
+        text(format!("⚠ STALE DATA ({}) | {}", error, base_status))
+            .size(14)
+            .font(Font::with_name("JetBrains Mono"))
+    } else {
+        text(base_status)
             .size(14)
             .font(Font::with_name("JetBrains Mono"))
     };
     
-    let content_column = column![
+    let mut content_column = column![
         ticker_input_field,
         price_chart_view,
         volume_chart_view,
-        status_bar,
     ]
     .spacing(20)
     .padding(20)
     .align_x(Alignment::Center);
 
-    container(content_column)
+    // Dedicated RSI pane, only when the indicator is toggled on.
+    if state.indicators.rsi {
+        content_column = content_column.push(
+            ChartWidget::new(&state.rsi_chart_state)
+                .width(Length::Fill)
+                .height(Length::FillPortion(2)),
+        );
+    }
+
+    let content_column = content_column.push(status_bar);
+
+    let main_content = container(content_column)
         .width(Length::Fill)
         .height(Length::Fill)
         .center_x(Length::Fill)
-        .center_y(Length::Fill)
+        .center_y(Length::Fill);
+
+    row![watchlist_panel(state), main_content]
+        .width(Length::Fill)
+        .height(Length::Fill)
         .into()
 }
 
+// Side column listing the watchlist symbols. Headers double as sort controls
+// (click to sort / flip direction) and each row selects that symbol.
+fn watchlist_panel(state: &StockScreener) -> Element<Message> {
+    let mono = Font::with_name("JetBrains Mono");
+
+    let header = |column: SortColumn| {
+        let mut label = column.label().to_string();
+        if state.sort_column == column {
+            label.push_str(if state.sort_ascending { " ^" } else { " v" });
+        }
+        button(text(label).size(13).font(mono))
+            .on_press(Message::SortWatchlist(column))
+            .padding(4)
+    };
+
+    let header_row = row![
+        header(SortColumn::Name),
+        header(SortColumn::Close),
+        header(SortColumn::Change),
+        header(SortColumn::Volume),
+    ]
+    .spacing(6);
+
+    let mut rows = column![].spacing(4);
+    for entry in &state.watchlist {
+        let line = format!(
+            "{:<6} {:>9.2} {:>8.2}% {:>12.0}",
+            entry.ticker, entry.close, entry.change_pct, entry.volume
+        );
+        rows = rows.push(
+            button(text(line).size(13).font(mono))
+                .on_press(Message::SelectTicker(entry.ticker.clone()))
+                .width(Length::Fill)
+                .padding(4),
+        );
+    }
+
+    let add_button = button(text("+ Add current ticker").size(13).font(mono))
+        .on_press(Message::AddToWatchlist)
+        .padding(4);
+
+    container(
+        column![
+            text("Watchlist").size(16).font(mono),
+            add_button,
+            header_row,
+            scrollable(rows).height(Length::Fill),
+        ]
+        .spacing(10)
+        .padding(10),
+    )
+    .width(Length::Fixed(360.0))
+    .height(Length::Fill)
+    .into()
+}
+
 // Theme function for the new Program API
 fn theme(_state: &StockScreener) -> Theme {
     Theme::custom("Dark".to_string(), theme::Palette {
@@ -285,13 +927,30 @@ fn theme(_state: &StockScreener) -> Theme {
 }
 
 // Subscription function for the new Program API
-fn subscription(_state: &StockScreener) -> Subscription<Message> {
+fn subscription(state: &StockScreener) -> Subscription<Message> {
     Subscription::batch([
+        // Long-lived background harvester that periodically re-fetches the
+        // active ticker and feeds the update loop through `Message::DataLoaded`.
+        Subscription::run(data_harvester),
+
+        // Watch the data directory so edits/regeneration of the active ticker's
+        // CSV refresh the charts without a manual reload. Keyed on the ticker so
+        // switching symbols re-targets the watcher.
+        Subscription::run_with_id(
+            state.ticker_input.to_uppercase(),
+            file_watcher(state.ticker_input.to_uppercase()),
+        ),
+
         // Reduced timer frequency to reduce rendering load
         time::every(Duration::from_millis(50)).map(|_| Message::UpdateCrosshairs), // ~20 FPS instead of 60
-        
-        // Event listener for keyboard and mouse
-        event::listen().map(|event| {
+
+        // Event listener for keyboard and mouse. `listen_with` exposes the event
+        // `Status`, so the single-character indicator/watchlist shortcuts can be
+        // ignored while the ticker `text_input` has focus and is capturing the
+        // keystroke — otherwise typing a symbol like "TSLA" would both enter the
+        // text and fire the s/a shortcuts. Ctrl combos and F11 never collide with
+        // text entry, so they stay active regardless of capture.
+        event::listen_with(|event, status, _window| {
             match event {
                 Event::Keyboard(keyboard::Event::KeyPressed {
                     key,
@@ -300,31 +959,188 @@ fn subscription(_state: &StockScreener) -> Subscription<Message> {
                 }) => {
                     if modifiers.control() {
                         match key.as_ref() {
-                            keyboard::Key::Character("q") | keyboard::Key::Character("w") => Message::CloseApp,
-                            _ => Message::NoOp,
+                            keyboard::Key::Character("q") | keyboard::Key::Character("w") => Some(Message::CloseApp),
+                            _ => None,
                         }
                     } else if modifiers.is_empty() {
                         match key.as_ref() {
-                            keyboard::Key::Named(keyboard::key::Named::F11) => Message::ToggleFullscreen,
-                            _ => Message::NoOp,
+                            keyboard::Key::Named(keyboard::key::Named::F11) => Some(Message::ToggleFullscreen),
+                            // Character shortcuts only when the keystroke was not
+                            // consumed by a focused widget (e.g. the ticker input).
+                            _ if status == event::Status::Captured => None,
+                            keyboard::Key::Character("r") => Some(Message::RefreshData),
+                            keyboard::Key::Character("s") => Some(Message::ToggleIndicator(Indicator::Sma)),
+                            keyboard::Key::Character("e") => Some(Message::ToggleIndicator(Indicator::Ema)),
+                            keyboard::Key::Character("b") => Some(Message::ToggleIndicator(Indicator::Bollinger)),
+                            keyboard::Key::Character("i") => Some(Message::ToggleIndicator(Indicator::Rsi)),
+                            keyboard::Key::Character("a") => Some(Message::AddToWatchlist),
+                            keyboard::Key::Character("o") => Some(Message::CycleWatchlistSort),
+                            keyboard::Key::Character("c") => Some(Message::CycleRefreshInterval),
+                            _ => None,
                         }
                     } else {
-                        Message::NoOp
+                        None
                     }
                 }
                 Event::Mouse(mouse::Event::CursorMoved { position }) => {
-                    Message::MouseMoved(position)
+                    Some(Message::MouseMoved(position))
+                }
+                Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                    // Use the vertical component of either scroll delta flavor.
+                    let amount = match delta {
+                        mouse::ScrollDelta::Lines { y, .. } => y,
+                        mouse::ScrollDelta::Pixels { y, .. } => y,
+                    };
+                    Some(Message::ScrollZoom(amount))
+                }
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                    Some(Message::DragStarted)
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                    Some(Message::DragEnded)
                 }
                 Event::Mouse(mouse::Event::CursorLeft) => {
                     // Hide crosshairs when mouse leaves the window
-                    Message::MouseMoved(Point::new(-1.0, -1.0)) // Use invalid coordinates to hide
+                    Some(Message::MouseMoved(Point::new(-1.0, -1.0))) // Use invalid coordinates to hide
                 }
-                _ => Message::NoOp,
+                Event::Window(window::Event::Resized(size)) => {
+                    // Track window size so the cursor->index mapping follows resizes.
+                    Some(Message::WindowResized(size))
+                }
+                _ => None,
             }
         })
     ])
 }
 
+// Background data-harvester: a long-lived subscription that periodically
+// re-fetches the active ticker and pushes `Message::DataLoaded` into the update
+// loop. On startup it hands a `HarvesterControl` sender back to the app via
+// `Message::HarvesterReady`, so the poll cadence and active symbol can be
+// retuned at runtime without tearing the subscription down — à la bottom's
+// harvester/`ThreadControlEvent` design.
+fn data_harvester() -> impl Stream<Item = Message> {
+    iced::stream::channel(16, |mut output| async move {
+        use iced::futures::FutureExt;
+
+        let (control_tx, mut control_rx) = mpsc::channel::<HarvesterControl>(16);
+        // Hand the control channel back to the app; if the receiver is already
+        // gone there is nothing left to drive.
+        if output.send(Message::HarvesterReady(control_tx)).await.is_err() {
+            return;
+        }
+
+        let mut ticker: Option<String> = None;
+        let mut interval = Duration::from_secs(60);
+
+        loop {
+            // Wake on whichever comes first: the next poll deadline or a control
+            // message retuning us. Fetches run inline, so the loop is naturally
+            // debounced — a slow fetch simply delays the next deadline rather
+            // than overlapping with it.
+            let tick = tokio::time::sleep(interval).fuse();
+            iced::futures::pin_mut!(tick);
+
+            iced::futures::select! {
+                control = control_rx.next() => {
+                    match control {
+                        Some(HarvesterControl::SetTicker(symbol)) => ticker = Some(symbol),
+                        Some(HarvesterControl::SetRefreshInterval(new_interval)) => {
+                            interval = new_interval;
+                            continue;
+                        }
+                        Some(HarvesterControl::Reset) => {}
+                        // App dropped the control channel: shut the harvester down.
+                        None => return,
+                    }
+                }
+                _ = tick => {}
+            }
+
+            if let Some(symbol) = ticker.clone() {
+                // Surface both fresh data and fetch errors through the same
+                // `DataLoaded` path the manual loader uses.
+                if output.send(Message::DataLoaded(load_stock_data(symbol).await)).await.is_err() {
+                    return;
+                }
+            }
+        }
+    })
+}
+
+// Filesystem-watch subsystem: watches the `historical_data` directory and fires
+// `Message::FileChanged` whenever the CSV matching `ticker` is created or
+// modified, so regenerating a file refreshes the charts live. The `notify`
+// watcher runs on its own thread (on macOS it uses the `macos_fsevent` backend);
+// its events are coalesced within a ~200ms window so a burst of writes triggers
+// a single reload.
+fn file_watcher(ticker: String) -> impl Stream<Item = Message> {
+    iced::stream::channel(16, |mut output| async move {
+        use iced::futures::FutureExt;
+        use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+        // Data directory lives in the crate directory's parent, as in `load_stock_data`.
+        let mut dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        dir.pop();
+        dir.push("historical_data");
+
+        let target = format!("{}.csv", ticker);
+
+        // `notify` delivers events on its own thread; bridge them onto an async
+        // channel we can debounce over.
+        let (mut tx, mut rx) = mpsc::channel::<()>(32);
+        let target_for_cb = target.clone();
+        let watcher = RecommendedWatcher::new(
+            move |res: notify::Result<notify::Event>| {
+                if let Ok(event) = res {
+                    if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                        let hit = event.paths.iter().any(|p| {
+                            p.file_name().map(|n| n == target_for_cb.as_str()).unwrap_or(false)
+                        });
+                        if hit {
+                            let _ = tx.try_send(());
+                        }
+                    }
+                }
+            },
+            notify::Config::default(),
+        );
+
+        // Keep the watcher alive for the lifetime of the subscription; bail out
+        // quietly if the directory cannot be watched.
+        let mut watcher = match watcher {
+            Ok(w) => w,
+            Err(_) => return,
+        };
+        if watcher.watch(&dir, RecursiveMode::NonRecursive).is_err() {
+            return;
+        }
+
+        loop {
+            // Block until the first change, then swallow any follow-up events
+            // that land within the debounce window before firing one reload.
+            if rx.next().await.is_none() {
+                return;
+            }
+            loop {
+                let debounce = tokio::time::sleep(Duration::from_millis(200)).fuse();
+                iced::futures::pin_mut!(debounce);
+                iced::futures::select! {
+                    next = rx.next().fuse() => {
+                        if next.is_none() {
+                            return;
+                        }
+                    }
+                    _ = debounce => break,
+                }
+            }
+            if output.send(Message::FileChanged(ticker.clone())).await.is_err() {
+                return;
+            }
+        }
+    })
+}
+
 // Asynchronous function to load stock data
 async fn load_stock_data(ticker: String) -> Result<Vec<StockData>, String> {
     // Construct the file path. Ensure your `historical_prices` directory is in the correct location.
@@ -352,31 +1168,114 @@ async fn load_stock_data(ticker: String) -> Result<Vec<StockData>, String> {
 struct ChartState {
     chart_type: ChartType,
     data: Vec<StockData>,
+    // Half-open index window `[start, end)` into `data` drawn on the x-axis.
+    visible_range: (usize, usize),
+    // Overlays to draw on top of the price series. The RSI pane draws its series
+    // regardless of this set; whether that pane is shown is decided in `view`.
+    indicators: IndicatorSet,
+    // Widget-relative pixel x-range of the plotting area, captured from plotters
+    // on each render so `update` can invert cursor x -> data index precisely.
+    plot_px: Cell<Option<(f64, f64)>>,
     mouse_position: Option<Point>,
     crosshair_visible: bool,
     last_crosshair_index: Option<usize>, // Track last crosshair position to reduce updates
+    // Shared selected index, resolved once from the price pane's pixel mapping and
+    // fed to the other panes so every crosshair lands on the same data point
+    // regardless of each pane's own y-axis label width.
+    selected_index: Option<usize>,
 }
 
 impl ChartState {
     fn new(chart_type: ChartType, data: Vec<StockData>) -> Self {
-        Self { 
-            chart_type, 
+        let visible_range = (0, data.len());
+        Self {
+            chart_type,
             data,
+            visible_range,
+            indicators: IndicatorSet::default(),
+            plot_px: Cell::new(None),
             mouse_position: None,
             crosshair_visible: false,
             last_crosshair_index: None,
+            selected_index: None,
         }
     }
 
     fn update_data(&mut self, new_data: Vec<StockData>) {
+        self.visible_range = (0, new_data.len());
         self.data = new_data;
         self.last_crosshair_index = None; // Reset crosshair when data changes
     }
+
+    fn set_visible_range(&mut self, range: (usize, usize)) {
+        self.visible_range = range;
+    }
+
+    fn set_indicators(&mut self, indicators: IndicatorSet) {
+        self.indicators = indicators;
+    }
+
+    // Closing prices of the full dataset, used to compute overlay series.
+    fn closes(&self) -> Vec<f64> {
+        self.data.iter().map(|d| d.close).collect()
+    }
+
+    // Record the plotting area's widget-relative pixel x-range for this render.
+    fn set_plot_px(&self, left: f64, right: f64) {
+        self.plot_px.set(Some((left, right)));
+    }
+
+    // Pixel width of the plotting area, if it has been captured yet.
+    fn plot_width_px(&self) -> Option<f32> {
+        self.plot_px.get().map(|(l, r)| (r - l) as f32)
+    }
+
+    // Fraction 0.0..=1.0 of the plotting area the window-space cursor sits at.
+    fn cursor_fraction(&self, cursor_x: f32) -> Option<f64> {
+        let (left, right) = self.plot_px.get()?;
+        if right <= left {
+            return None;
+        }
+        let rel_x = (cursor_x - CHART_WIDGET_LEFT) as f64;
+        Some(((rel_x - left) / (right - left)).clamp(0.0, 1.0))
+    }
+
+    // Invert the captured pixel mapping to the data index under the cursor,
+    // or `None` if the cursor is outside the plotting area / no data is loaded.
+    fn index_at_cursor(&self, cursor_x: f32) -> Option<usize> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let (left, right) = self.plot_px.get()?;
+        let rel_x = (cursor_x - CHART_WIDGET_LEFT) as f64;
+        if rel_x < left || rel_x > right {
+            return None;
+        }
+        let (lo, hi) = self.visible_bounds();
+        let width = hi - lo;
+        let frac = self.cursor_fraction(cursor_x)?;
+        Some((lo + ((frac * width as f64) as usize).min(width - 1)).min(self.data.len() - 1))
+    }
+
+    // Clamp the stored window to the current data bounds, yielding a usable
+    // `[start, end)` slice with `start < end`.
+    fn visible_bounds(&self) -> (usize, usize) {
+        let len = self.data.len();
+        let end = self.visible_range.1.min(len).max(1);
+        let start = self.visible_range.0.min(end - 1);
+        (start, end)
+    }
     
     fn set_mouse_position(&mut self, position: Option<Point>) {
         self.mouse_position = position;
         self.crosshair_visible = position.is_some();
     }
+
+    // Share the index the price pane resolved from the cursor, so dependent panes
+    // (e.g. volume) draw their crosshair at the exact same data point.
+    fn set_selected_index(&mut self, index: Option<usize>) {
+        self.selected_index = index;
+    }
 }
 
 impl Chart<Message> for ChartState {
@@ -395,19 +1294,26 @@ impl Chart<Message> for ChartState {
             return;
         }
 
-        let x_range = 0.0..(self.data.len() as f64);
+        let (vis_lo, vis_hi) = self.visible_bounds();
+        let x_range = vis_lo as f64..vis_hi as f64;
 
         match self.chart_type {
             ChartType::Price => {
-                let (min_low, max_high) = self.data.iter()
+                // Scale the y-axis to just the visible slice so zoom/pan stays legible.
+                let (min_low, max_high) = self.data[vis_lo..vis_hi].iter()
                     .map(|d| (d.low, d.high))
-                    .fold((self.data[0].low, self.data[0].high), |(min_l, max_h), (l, h)| (min_l.min(l), max_h.max(h)));
+                    .fold((self.data[vis_lo].low, self.data[vis_lo].high), |(min_l, max_h), (l, h)| (min_l.min(l), max_h.max(h)));
 
                 let mut price_chart_context = chart_builder
                     .margin(5)
                     .build_cartesian_2d(x_range.clone(), min_low..max_high)
                     .expect("Failed to build price chart");
 
+                // Capture the real plotting-area pixel bounds so `update` can
+                // map the cursor back to a data index without magic numbers.
+                let (px_x, _px_y) = price_chart_context.plotting_area().get_pixel_range();
+                self.set_plot_px(px_x.start as f64, px_x.end as f64);
+
                 price_chart_context.configure_mesh()
                     .set_all_tick_mark_size(0)
                     .disable_x_mesh()
@@ -415,71 +1321,106 @@ impl Chart<Message> for ChartState {
                     .bold_line_style(WHITE.mix(0.05).stroke_width(1))
                     .draw().expect("Failed to draw price mesh");
 
-                price_chart_context.draw_series(self.data.iter().enumerate().map(|(idx, data)| {
+                // Size the candle body to the visible window: as the user zooms
+                // out and more candles share the same plotting width, the bodies
+                // shrink instead of overlapping into a solid block. Leave ~20% of
+                // the per-candle slot as a gap, matching the volume bars' 0.8.
+                let visible_count = (vis_hi - vis_lo).max(1);
+                let slot_px = (px_x.end - px_x.start) as f64 / visible_count as f64;
+                let candle_px = (slot_px * 0.8).floor().clamp(1.0, 15.0) as u32;
+
+                // Only iterate the visible slice so zoomed-out views do not redraw
+                // the entire CSV history every frame.
+                price_chart_context.draw_series((vis_lo..vis_hi).map(|idx| {
+                    let data = &self.data[idx];
                     let x = idx as f64;
                     let open = data.open;
                     let high = data.high;
                     let low = data.low;
                     let close = data.close;
                     let color = if close >= open { GREEN } else { RED };
-                    CandleStick::new(x, open, high, low, close, color.filled(), color, 10)
+                    CandleStick::new(x, open, high, low, close, color.filled(), color, candle_px)
                 })).expect("Failed to draw candlestick series");
 
+                // Overlay enabled technical indicators. Each is drawn as a
+                // LineSeries restricted to the visible window, skipping the
+                // leading points where the indicator is still undefined.
+                let closes = self.closes();
+                if self.indicators.sma {
+                    let series = indicators::sma(&closes, SMA_PERIOD);
+                    let points: Vec<(f64, f64)> = (vis_lo..vis_hi)
+                        .filter_map(|i| series[i].map(|v| (i as f64, v)))
+                        .collect();
+                    price_chart_context.draw_series(LineSeries::new(points, YELLOW.stroke_width(2)))
+                        .expect("Failed to draw SMA overlay");
+                }
+                if self.indicators.ema {
+                    let series = indicators::ema(&closes, EMA_PERIOD);
+                    let points: Vec<(f64, f64)> = (vis_lo..vis_hi)
+                        .filter_map(|i| series[i].map(|v| (i as f64, v)))
+                        .collect();
+                    price_chart_context.draw_series(LineSeries::new(points, CYAN.stroke_width(2)))
+                        .expect("Failed to draw EMA overlay");
+                }
+                if self.indicators.bollinger {
+                    let (_middle, upper, lower) = indicators::bollinger(&closes, BOLLINGER_PERIOD, BOLLINGER_MULT);
+                    for band in [&upper, &lower] {
+                        let points: Vec<(f64, f64)> = (vis_lo..vis_hi)
+                            .filter_map(|i| band[i].map(|v| (i as f64, v)))
+                            .collect();
+                        price_chart_context.draw_series(LineSeries::new(points, MAGENTA.mix(0.8).stroke_width(1)))
+                            .expect("Failed to draw Bollinger band");
+                    }
+                }
+
                 // Draw crosshairs if mouse is over the chart
                 if self.crosshair_visible {
                     if let Some(mouse_pos) = self.mouse_position {
-                        if self.data.len() > 0 {
-                            let data_count = self.data.len();
-                            
-                            // Use the exact same calculation as in mouse tracking
-                            let chart_left_margin = 60.0;
-                            let chart_right_margin = 60.0;
-                            let window_width = 1800.0;
-                            let chart_width = window_width - chart_left_margin - chart_right_margin;
-                            
-                            if mouse_pos.x >= chart_left_margin && mouse_pos.x <= (window_width - chart_right_margin) {
-                                let relative_x = mouse_pos.x - chart_left_margin;
-                                let ratio = relative_x / chart_width;
-                                let data_index = (ratio * data_count as f32) as usize;
-                                let data_index = data_index.min(data_count - 1);
-                                
-                                let x_pos = data_index as f64;
-                                let data_point = &self.data[data_index];
-                                
-                                // Draw vertical crosshair line with semi-transparent white
-                                price_chart_context.draw_series(std::iter::once(
-                                    PathElement::new(vec![(x_pos, min_low), (x_pos, max_high)], WHITE.mix(0.6).stroke_width(1))
-                                )).expect("Failed to draw vertical crosshair");
-                                
-                                // Draw horizontal crosshair line at close price
-                                price_chart_context.draw_series(std::iter::once(
-                                    PathElement::new(vec![(0.0, data_point.close), (self.data.len() as f64, data_point.close)], WHITE.mix(0.6).stroke_width(1))
-                                )).expect("Failed to draw horizontal crosshair");
-                                
-                                // Draw a small circle at the intersection point
-                                price_chart_context.draw_series(std::iter::once(
-                                    Circle::new((x_pos, data_point.close), 2, WHITE.filled())
-                                )).expect("Failed to draw crosshair intersection");
-                            }
+                        // Map the cursor onto an index via the captured pixel
+                        // mapping, matching the selection logic in `update`.
+                        if let Some(data_index) = self.index_at_cursor(mouse_pos.x) {
+                            let x_pos = data_index as f64;
+                            let data_point = &self.data[data_index];
+
+                            // Draw vertical crosshair line with semi-transparent white
+                            price_chart_context.draw_series(std::iter::once(
+                                PathElement::new(vec![(x_pos, min_low), (x_pos, max_high)], WHITE.mix(0.6).stroke_width(1))
+                            )).expect("Failed to draw vertical crosshair");
+
+                            // Draw horizontal crosshair line at close price
+                            price_chart_context.draw_series(std::iter::once(
+                                PathElement::new(vec![(vis_lo as f64, data_point.close), (vis_hi as f64, data_point.close)], WHITE.mix(0.6).stroke_width(1))
+                            )).expect("Failed to draw horizontal crosshair");
+
+                            // Draw a small circle at the intersection point
+                            price_chart_context.draw_series(std::iter::once(
+                                Circle::new((x_pos, data_point.close), 2, WHITE.filled())
+                            )).expect("Failed to draw crosshair intersection");
                         }
                     }
                 }
             }
             ChartType::Volume => {
-                let max_volume = self.data.iter().map(|d| d.volume).fold(0.0, f64::max);
+                let max_volume = self.data[vis_lo..vis_hi].iter().map(|d| d.volume).fold(0.0, f64::max);
 
                 let mut volume_chart_context = chart_builder
                     .margin(5)
                     .build_cartesian_2d(x_range.clone(), 0.0..max_volume)
                     .expect("Failed to build volume chart");
 
+                // Capture this pane's plotting-area bounds as well; its crosshair
+                // below is placed from the shared selected index, not from these.
+                let (px_x, _px_y) = volume_chart_context.plotting_area().get_pixel_range();
+                self.set_plot_px(px_x.start as f64, px_x.end as f64);
+
                 volume_chart_context.configure_mesh()
                     .set_all_tick_mark_size(0)
                     .disable_x_mesh()
                     .axis_style(BLACK)
                     .draw().expect("Failed to draw volume mesh");
 
-                volume_chart_context.draw_series(self.data.iter().enumerate().map(|(idx, data)| {
+                volume_chart_context.draw_series((vis_lo..vis_hi).map(|idx| {
+                    let data = &self.data[idx];
                     let x = idx as f64;
                     let color = if data.close >= data.open { GREEN.mix(0.5) } else { RED.mix(0.5) };
                     let bar_width = 0.8f64;
@@ -489,7 +1430,46 @@ impl Chart<Message> for ChartState {
                     ], color.filled())
                 })).expect("Failed to draw volume series");
 
-                // No crosshairs for volume chart - keeps it cleaner
+                // Synchronized vertical crosshair at the shared selected index so
+                // the volume pane tracks the price pane exactly under the cursor.
+                // Using the index resolved from the price pane (not this pane's own
+                // pixel mapping) keeps the two crosshairs aligned even though the
+                // panes have different y-axis label widths.
+                if self.crosshair_visible {
+                    if let Some(data_index) = self.selected_index {
+                        let x_pos = data_index as f64;
+                        volume_chart_context.draw_series(std::iter::once(
+                            PathElement::new(vec![(x_pos, 0.0), (x_pos, max_volume)], WHITE.mix(0.6).stroke_width(1))
+                        )).expect("Failed to draw volume crosshair");
+                    }
+                }
+            }
+            ChartType::Rsi => {
+                let mut rsi_chart_context = chart_builder
+                    .margin(5)
+                    .build_cartesian_2d(x_range.clone(), 0.0..100.0)
+                    .expect("Failed to build RSI chart");
+
+                rsi_chart_context.configure_mesh()
+                    .set_all_tick_mark_size(0)
+                    .disable_x_mesh()
+                    .axis_style(BLACK)
+                    .bold_line_style(WHITE.mix(0.05).stroke_width(1))
+                    .draw().expect("Failed to draw RSI mesh");
+
+                // Overbought/oversold guide lines at 70 and 30.
+                for level in [70.0, 30.0] {
+                    rsi_chart_context.draw_series(std::iter::once(
+                        PathElement::new(vec![(vis_lo as f64, level), (vis_hi as f64, level)], WHITE.mix(0.2).stroke_width(1))
+                    )).expect("Failed to draw RSI guide line");
+                }
+
+                let series = indicators::rsi(&self.closes(), RSI_PERIOD);
+                let points: Vec<(f64, f64)> = (vis_lo..vis_hi)
+                    .filter_map(|i| series[i].map(|v| (i as f64, v)))
+                    .collect();
+                rsi_chart_context.draw_series(LineSeries::new(points, MAGENTA.stroke_width(2)))
+                    .expect("Failed to draw RSI series");
             }
         }
     }